@@ -0,0 +1,76 @@
+use std::{
+    io::{self, Write},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use bytes::BytesMut;
+use futures::AsyncRead;
+use pyo3::prelude::*;
+
+use crate::reader::PyBytesReader;
+
+const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+struct BufReaderState {
+    inner: PyBytesReader,
+    buf: BytesMut,
+    capacity: usize,
+}
+
+/// Buffers reads from a `PyBytesReader`, coalescing the many small
+/// `Py<PyBytes>` items the channel may hold into fewer, larger reads of up
+/// to `capacity` bytes. Filling the buffer still takes the GIL once per
+/// channel item; reads served from the buffer take no GIL.
+#[pyclass]
+#[derive(Clone)]
+pub struct BufReader {
+    state: Arc<Mutex<BufReaderState>>,
+}
+
+impl BufReader {
+    pub fn new(inner: PyBytesReader) -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY, inner)
+    }
+
+    pub fn with_capacity(capacity: usize, inner: PyBytesReader) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(BufReaderState {
+                inner,
+                buf: BytesMut::with_capacity(capacity),
+                capacity,
+            })),
+        }
+    }
+}
+
+impl AsyncRead for BufReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        mut out: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let mut state = self.state.lock().unwrap();
+
+        if state.buf.is_empty() {
+            let capacity = state.capacity;
+            let mut chunk = vec![0; capacity];
+
+            match Pin::new(&mut state.inner).poll_read(cx, &mut chunk) {
+                Poll::Ready(Ok(0)) => return Poll::Ready(Ok(0)),
+                Poll::Ready(Ok(n)) => {
+                    chunk.truncate(n);
+                    state.buf.extend_from_slice(&chunk);
+                }
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        let n = out.write(&state.buf).unwrap();
+        let _ = state.buf.split_to(n);
+
+        Poll::Ready(Ok(n))
+    }
+}
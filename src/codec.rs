@@ -0,0 +1,257 @@
+use std::{
+    io,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use bytes::BytesMut;
+use futures::{lock::Mutex, ready, AsyncRead, AsyncWrite, Sink, Stream, StreamExt};
+use pyo3::{exceptions::PyStopAsyncIteration, prelude::*, types::PyBytes};
+
+use crate::reader::PyBytesReader;
+
+const INITIAL_CAPACITY: usize = 8 * 1024;
+
+/// Decodes a stream of bytes into a stream of frames.
+pub trait Decoder {
+    type Item;
+    type Error: From<io::Error>;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error>;
+
+    /// Called once the underlying stream has reached EOF, to collect any
+    /// frame that was still buffered up. The default implementation errors
+    /// out if bytes are left over, since a well-formed stream should not
+    /// end mid-frame.
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self.decode(src)? {
+            Some(item) => Ok(Some(item)),
+            None if src.is_empty() => Ok(None),
+            None => Err(
+                io::Error::new(io::ErrorKind::UnexpectedEof, "bytes remaining on stream").into(),
+            ),
+        }
+    }
+}
+
+/// Encodes frames into a buffer of bytes to be written to a sink.
+pub trait Encoder<Item> {
+    fn encode(&mut self, item: Item, dst: &mut BytesMut);
+}
+
+/// Splits an `AsyncRead` into a `Stream` of frames, using a [`Decoder`] to
+/// find frame boundaries in the buffered bytes.
+pub struct FramedRead<R, D> {
+    inner: R,
+    decoder: D,
+    buffer: BytesMut,
+    eof: bool,
+}
+
+impl<R, D> FramedRead<R, D> {
+    pub fn new(inner: R, decoder: D) -> Self {
+        Self {
+            inner,
+            decoder,
+            buffer: BytesMut::with_capacity(INITIAL_CAPACITY),
+            eof: false,
+        }
+    }
+}
+
+impl<R, D> Stream for FramedRead<R, D>
+where
+    R: AsyncRead + Unpin,
+    D: Decoder + Unpin,
+{
+    type Item = Result<D::Item, D::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(item) = this.decoder.decode(&mut this.buffer).transpose() {
+                return Poll::Ready(Some(item));
+            }
+
+            if this.eof {
+                return Poll::Ready(this.decoder.decode_eof(&mut this.buffer).transpose());
+            }
+
+            let mut read_buf = [0u8; INITIAL_CAPACITY];
+            match Pin::new(&mut this.inner).poll_read(cx, &mut read_buf) {
+                Poll::Ready(Ok(0)) => this.eof = true,
+                Poll::Ready(Ok(n)) => this.buffer.extend_from_slice(&read_buf[..n]),
+                Poll::Ready(Err(e)) => return Poll::Ready(Some(Err(e.into()))),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Turns an `AsyncWrite` into a `Sink` of frames, using an [`Encoder`] to
+/// serialize each frame into the staging buffer before draining it.
+pub struct FramedWrite<W, E> {
+    inner: W,
+    encoder: E,
+    buffer: BytesMut,
+}
+
+impl<W, E> FramedWrite<W, E> {
+    pub fn new(inner: W, encoder: E) -> Self {
+        Self {
+            inner,
+            encoder,
+            buffer: BytesMut::new(),
+        }
+    }
+}
+
+impl<W, E, Item> Sink<Item> for FramedWrite<W, E>
+where
+    W: AsyncWrite + Unpin,
+    E: Encoder<Item> + Unpin,
+{
+    type Error = io::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+        this.encoder.encode(item, &mut this.buffer);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+
+        while !this.buffer.is_empty() {
+            match Pin::new(&mut this.inner).poll_write(cx, &this.buffer) {
+                Poll::Ready(Ok(0)) => {
+                    return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+                }
+                Poll::Ready(Ok(n)) => {
+                    let _ = this.buffer.split_to(n);
+                }
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        ready!(self.as_mut().poll_flush(cx))?;
+        let this = self.get_mut();
+        Pin::new(&mut this.inner).poll_close(cx)
+    }
+}
+
+/// Frames a byte stream using a `u32` big-endian length prefix.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LengthDelimitedCodec;
+
+impl Decoder for LengthDelimitedCodec {
+    type Item = BytesMut;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        if src.len() < 4 {
+            return Ok(None);
+        }
+
+        let len = u32::from_be_bytes(src[..4].try_into().unwrap()) as usize;
+
+        if src.len() < 4 + len {
+            src.reserve(4 + len - src.len());
+            return Ok(None);
+        }
+
+        let mut frame = src.split_to(4 + len);
+        let _ = frame.split_to(4);
+        Ok(Some(frame))
+    }
+}
+
+impl Encoder<BytesMut> for LengthDelimitedCodec {
+    fn encode(&mut self, item: BytesMut, dst: &mut BytesMut) {
+        dst.extend_from_slice(&(item.len() as u32).to_be_bytes());
+        dst.extend_from_slice(&item);
+    }
+}
+
+/// Frames a byte stream by splitting on `\n`. The delimiter is consumed but
+/// not included in the yielded frame.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LinesCodec;
+
+impl Decoder for LinesCodec {
+    type Item = BytesMut;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match src.iter().position(|b| *b == b'\n') {
+            Some(pos) => {
+                let mut line = src.split_to(pos + 1);
+                line.truncate(pos);
+                Ok(Some(line))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// A final line with no trailing `\n` is still a line - flush whatever
+    /// is left in `src` instead of erroring like the default `decode_eof`.
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self.decode(src)? {
+            Some(item) => Ok(Some(item)),
+            None if src.is_empty() => Ok(None),
+            None => Ok(Some(src.split_to(src.len()))),
+        }
+    }
+}
+
+impl Encoder<BytesMut> for LinesCodec {
+    fn encode(&mut self, item: BytesMut, dst: &mut BytesMut) {
+        dst.extend_from_slice(&item);
+        dst.extend_from_slice(b"\n");
+    }
+}
+
+/// Python-facing async iterator that splits a `py_bytes` channel into
+/// length-delimited frames, yielding one `bytes` object per frame.
+#[pyclass]
+#[derive(Clone)]
+pub struct PyFramedBytesReader {
+    inner: Arc<Mutex<FramedRead<PyBytesReader, LengthDelimitedCodec>>>,
+}
+
+impl PyFramedBytesReader {
+    pub(crate) fn new(reader: PyBytesReader) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(FramedRead::new(reader, LengthDelimitedCodec))),
+        }
+    }
+}
+
+#[pymethods]
+impl PyFramedBytesReader {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    async fn __anext__(&self) -> PyResult<Py<PyBytes>> {
+        let mut framed = self.inner.lock().await;
+        match framed.next().await {
+            Some(Ok(frame)) => Ok(Python::with_gil(|py| {
+                PyBytes::new_bound(py, &frame).unbind()
+            })),
+            Some(Err(e)) => Err(e.into()),
+            None => Err(PyStopAsyncIteration::new_err(())),
+        }
+    }
+}
@@ -10,15 +10,19 @@ use std::{
 };
 
 use crossbeam_queue::ArrayQueue;
-use futures::{sink::Sink, task::AtomicWaker, AsyncRead, Stream};
+use futures::{sink::Sink, task::AtomicWaker, AsyncRead, AsyncWrite, Stream};
 use pyo3::{
     exceptions::{self},
     prelude::*,
     types::PyBytes,
 };
 
+pub mod buf;
+pub mod codec;
+mod object;
 mod py;
 pub mod reader;
+pub mod writer;
 
 #[derive(Debug, PartialEq)]
 #[pyclass(eq, eq_int)]
@@ -161,7 +165,7 @@ macro_rules! specialized_pychan {
             #[pin_project::pin_project]
             pub struct $sender_name {
                 #[pin]
-                writer: PySender<$item>,
+                pub(crate) writer: PySender<$item>,
             }
 
             impl Sink<Py<$item>> for $sender_name {
@@ -236,3 +240,10 @@ specialized_pychan!(
     pyo3::types::PyBytes,
     py_bytes
 );
+
+specialized_pychan!(
+    PyObjectReceiver,
+    PyObjectSender,
+    pyo3::types::PyAny,
+    py_object
+);
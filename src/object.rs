@@ -0,0 +1,19 @@
+use futures::StreamExt;
+use pyo3::{exceptions::PyStopAsyncIteration, prelude::*, types::PyAny};
+
+use crate::py_object::PyObjectReceiver;
+
+#[pymethods]
+impl PyObjectReceiver {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    async fn __anext__(&self) -> PyResult<Py<PyAny>> {
+        let mut this = self.clone();
+        match this.next().await {
+            Some(obj) => Ok(obj),
+            None => Err(PyStopAsyncIteration::new_err(())),
+        }
+    }
+}
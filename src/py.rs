@@ -1,7 +1,19 @@
-use futures::{AsyncReadExt, SinkExt};
-use pyo3::{prelude::*, types::PyBytes};
+use std::task::{Context, Poll};
 
-use crate::{py_bytes::PyBytesSender, reader::PyBytesReader};
+use futures::{task::noop_waker, AsyncReadExt, AsyncWriteExt, SinkExt, StreamExt};
+use pyo3::{
+    prelude::*,
+    types::{PyAny, PyBytes},
+};
+
+use crate::{
+    buf::BufReader,
+    codec::PyFramedBytesReader,
+    py_bytes::{PyBytesReceiver, PyBytesSender},
+    py_object::{PyObjectReceiver, PyObjectSender},
+    reader::PyBytesReader,
+    writer::PyBytesWriter,
+};
 
 #[pyfunction]
 fn bytes_chan(capacity: usize) -> (PyBytesSender, PyBytesReader) {
@@ -10,6 +22,13 @@ fn bytes_chan(capacity: usize) -> (PyBytesSender, PyBytesReader) {
     (sender, reader)
 }
 
+#[pyfunction]
+fn bytes_chan_writer(capacity: usize) -> (PyBytesWriter, PyBytesReceiver) {
+    let (sender, receiver) = crate::py_bytes::channel(capacity);
+    let writer = sender.into_writer();
+    (writer, receiver)
+}
+
 #[pyfunction]
 async fn chan_send(mut sender: PyBytesSender, bytes: Py<PyBytes>) -> PyResult<()> {
     sender.send(bytes).await?;
@@ -23,8 +42,46 @@ async fn sender_close(mut sender: PyBytesSender) -> PyResult<()> {
 }
 
 #[pyfunction]
-async fn chan_read(mut reader: PyBytesReader, bytes: usize) -> PyResult<Py<PyBytes>> {
-    // TODO would be great if we could avoid zeroing the buffer
+async fn chan_write(mut writer: PyBytesWriter, bytes: Py<PyBytes>) -> PyResult<usize> {
+    let buf = Python::with_gil(|py| bytes.as_bytes(py).to_vec());
+    writer.write(&buf).await?;
+    Ok(buf.len())
+}
+
+#[pyfunction]
+fn framed_bytes_reader(reader: PyBytesReader) -> PyFramedBytesReader {
+    PyFramedBytesReader::new(reader)
+}
+
+#[pyfunction]
+fn object_chan(capacity: usize) -> (PyObjectSender, PyObjectReceiver) {
+    crate::py_object::channel(capacity)
+}
+
+#[pyfunction]
+async fn obj_send(mut sender: PyObjectSender, obj: Py<PyAny>) -> PyResult<()> {
+    sender.send(obj).await?;
+    Ok(())
+}
+
+#[pyfunction]
+async fn obj_recv(mut receiver: PyObjectReceiver) -> PyResult<Option<Py<PyAny>>> {
+    Ok(receiver.next().await)
+}
+
+#[pyfunction]
+async fn obj_sender_close(mut sender: PyObjectSender) -> PyResult<()> {
+    sender.close().await?;
+    Ok(())
+}
+
+#[pyfunction]
+fn buffered_bytes_reader(reader: PyBytesReader, capacity: usize) -> BufReader {
+    BufReader::with_capacity(capacity, reader)
+}
+
+#[pyfunction]
+async fn chan_read_buffered(mut reader: BufReader, bytes: usize) -> PyResult<Py<PyBytes>> {
     let mut buf = vec![0; bytes];
     let n = reader.read(&mut buf).await?;
     let bytes = Python::with_gil(|py| PyBytes::new_bound(py, &buf[..n]).unbind());
@@ -32,12 +89,139 @@ async fn chan_read(mut reader: PyBytesReader, bytes: usize) -> PyResult<Py<PyByt
     Ok(bytes)
 }
 
+// Relies on `PyBytesReader::poll_fill` waking a full destination's writer
+// on drain (see its `AsyncRead` impl) - otherwise a write blocking on a
+// full destination channel would never be retried.
+#[pyfunction]
+async fn chan_copy(mut reader: PyBytesReader, mut writer: PyBytesWriter) -> PyResult<usize> {
+    let mut buf = [0u8; 8 * 1024];
+    let mut total = 0;
+
+    loop {
+        let n = reader.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+
+        writer.write_all(&buf[..n]).await?;
+        total += n;
+    }
+
+    Ok(total)
+}
+
+#[pyfunction]
+async fn chan_read(mut reader: PyBytesReader, bytes: usize) -> PyResult<Py<PyBytes>> {
+    // `PyBytes::new_bound_with`'s init closure is synchronous, so we first
+    // await until data (or closure) is actually available, then fill the
+    // freshly allocated, uninitialized buffer in one non-blocking pass.
+    // `PyBytesReader` is `Clone`/`Arc`-shared, so another consumer can still
+    // steal the data between the two steps; an empty `bytes` would be
+    // indistinguishable from EOF to Python, so on that race we loop back
+    // and wait again instead of surfacing it as a short read.
+    loop {
+        let has_data = futures::future::poll_fn(|cx| reader.poll_has_data(cx)).await?;
+
+        if !has_data {
+            return Ok(Python::with_gil(|py| PyBytes::new_bound(py, &[]).unbind()));
+        }
+
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let mut raced = false;
+        let mut n = 0;
+        let full = Python::with_gil(|py| -> PyResult<Py<PyBytes>> {
+            let obj =
+                PyBytes::new_bound_with(py, bytes, |buf| match reader.poll_fill(&mut cx, buf) {
+                    Poll::Ready(Ok(read)) => {
+                        n = read;
+                        Ok(())
+                    }
+                    Poll::Ready(Err(e)) => Err(e.into()),
+                    Poll::Pending => {
+                        raced = true;
+                        Ok(())
+                    }
+                })?;
+            Ok(obj.unbind())
+        })?;
+
+        if raced {
+            continue;
+        }
+
+        if n == bytes {
+            return Ok(full);
+        }
+
+        let bytes = Python::with_gil(|py| PyBytes::new_bound(py, &full.as_bytes(py)[..n]).unbind());
+
+        return Ok(bytes);
+    }
+}
+
+#[pyfunction]
+async fn chan_read_exact(mut reader: PyBytesReader, n: usize) -> PyResult<Py<PyBytes>> {
+    let mut buf = vec![0; n];
+    reader.read_exact(&mut buf).await?;
+    let bytes = Python::with_gil(|py| PyBytes::new_bound(py, &buf).unbind());
+
+    Ok(bytes)
+}
+
+#[pyfunction]
+async fn chan_read_to_end(mut reader: PyBytesReader) -> PyResult<Py<PyBytes>> {
+    let mut buf = Vec::new();
+    reader.read_to_end(&mut buf).await?;
+    let bytes = Python::with_gil(|py| PyBytes::new_bound(py, &buf).unbind());
+
+    Ok(bytes)
+}
+
+#[pyfunction]
+async fn chan_read_until(mut reader: PyBytesReader, delim: u8) -> PyResult<Py<PyBytes>> {
+    let mut buf = Vec::new();
+    reader.read_until(delim, &mut buf).await?;
+    let bytes = Python::with_gil(|py| PyBytes::new_bound(py, &buf).unbind());
+
+    Ok(bytes)
+}
+
+#[pyfunction]
+async fn chan_read_line(mut reader: PyBytesReader) -> PyResult<Py<PyBytes>> {
+    let mut buf = Vec::new();
+    reader.read_line(&mut buf).await?;
+    let bytes = Python::with_gil(|py| PyBytes::new_bound(py, &buf).unbind());
+
+    Ok(bytes)
+}
+
 #[pymodule]
 fn pychan(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(bytes_chan, m)?)?;
+    m.add_function(wrap_pyfunction!(bytes_chan_writer, m)?)?;
     m.add_function(wrap_pyfunction!(chan_send, m)?)?;
     m.add_function(wrap_pyfunction!(chan_read, m)?)?;
+    m.add_function(wrap_pyfunction!(chan_read_exact, m)?)?;
+    m.add_function(wrap_pyfunction!(chan_read_to_end, m)?)?;
+    m.add_function(wrap_pyfunction!(chan_read_until, m)?)?;
+    m.add_function(wrap_pyfunction!(chan_read_line, m)?)?;
+    m.add_function(wrap_pyfunction!(chan_write, m)?)?;
     m.add_function(wrap_pyfunction!(sender_close, m)?)?;
+    m.add_function(wrap_pyfunction!(framed_bytes_reader, m)?)?;
+    m.add_function(wrap_pyfunction!(object_chan, m)?)?;
+    m.add_function(wrap_pyfunction!(obj_send, m)?)?;
+    m.add_function(wrap_pyfunction!(obj_recv, m)?)?;
+    m.add_function(wrap_pyfunction!(obj_sender_close, m)?)?;
+    m.add_function(wrap_pyfunction!(buffered_bytes_reader, m)?)?;
+    m.add_function(wrap_pyfunction!(chan_read_buffered, m)?)?;
+    m.add_function(wrap_pyfunction!(chan_copy, m)?)?;
     m.add_class::<PyBytesSender>()?;
+    m.add_class::<PyBytesWriter>()?;
+    m.add_class::<PyFramedBytesReader>()?;
+    m.add_class::<PyObjectSender>()?;
+    m.add_class::<PyObjectReceiver>()?;
+    m.add_class::<BufReader>()?;
     Ok(())
 }
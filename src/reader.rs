@@ -1,9 +1,14 @@
+use std::io;
+
 use crossbeam_utils::atomic::AtomicCell;
+use futures::AsyncReadExt;
 
 use self::py_bytes::PyBytesReceiver;
 
 use super::*;
 
+const READ_UNTIL_CHUNK_SIZE: usize = 8 * 1024;
+
 impl PyBytesReceiver {
     pub fn into_reader(self) -> PyBytesReader {
         PyBytesReader::new(self.reader.inner)
@@ -38,14 +43,104 @@ impl PyBytesReader {
             has_scratch
         }
     }
-}
 
-impl AsyncRead for PyBytesReader {
-    fn poll_read(
-        mut self: Pin<&mut Self>,
+    /// Reads until exactly `buf.len()` bytes have been collected, mirroring
+    /// `AsyncReadExt::read_exact`'s `UnexpectedEof` behaviour when the
+    /// channel closes early.
+    pub async fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        AsyncReadExt::read_exact(self, buf).await
+    }
+
+    /// Reads chunks until the channel is closed, appending them to `buf`.
+    /// Returns the number of bytes read.
+    pub async fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        AsyncReadExt::read_to_end(self, buf).await
+    }
+
+    /// Reads bytes into `buf` up to and including the first occurrence of
+    /// `delim`, or until the channel closes. Any bytes read past `delim` are
+    /// pushed back into `scratch` so the next read picks up where this one
+    /// left off.
+    pub async fn read_until(&mut self, delim: u8, buf: &mut Vec<u8>) -> io::Result<usize> {
+        let mut chunk = [0u8; READ_UNTIL_CHUNK_SIZE];
+        let mut total = 0;
+
+        loop {
+            let n = AsyncReadExt::read(self, &mut chunk).await?;
+            if n == 0 {
+                return Ok(total);
+            }
+
+            if let Some(pos) = chunk[..n].iter().position(|b| *b == delim) {
+                buf.extend_from_slice(&chunk[..=pos]);
+                total += pos + 1;
+
+                if pos + 1 < n {
+                    self.push_back(&chunk[pos + 1..n]);
+                }
+
+                return Ok(total);
+            }
+
+            buf.extend_from_slice(&chunk[..n]);
+            total += n;
+        }
+    }
+
+    /// Reads a single line, i.e. everything up to and including the next
+    /// `\n`.
+    pub async fn read_line(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        self.read_until(b'\n', buf).await
+    }
+
+    /// Prepends `leftover` to whatever is already sitting in `scratch`,
+    /// rather than clobbering it. `poll_fill` may have stashed the
+    /// remainder of an oversized item there while filling our `chunk`
+    /// buffer, and that already-scratched data comes after `leftover` in
+    /// the stream, so it must be kept, not dropped.
+    fn push_back(&self, leftover: &[u8]) {
+        if leftover.is_empty() {
+            return;
+        }
+
+        let existing = self.scratch.take();
+        let bytes = Python::with_gil(|py| {
+            let mut combined = leftover.to_vec();
+            if let Some(PyBytesReaderScratch { cursor, bytes }) = &existing {
+                combined.extend_from_slice(&bytes.as_bytes(py)[*cursor..]);
+            }
+            PyBytes::new_bound(py, &combined).unbind()
+        });
+
+        self.scratch
+            .store(Some(PyBytesReaderScratch { cursor: 0, bytes }));
+    }
+
+    /// Resolves once either the scratch slot or the channel's `ArrayQueue`
+    /// holds data, or the channel is closed. Lets callers that can't await
+    /// inside their actual read step (e.g. a `PyBytes` init closure) wait
+    /// for readiness first and then fill synchronously.
+    pub(crate) fn poll_has_data(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<bool>> {
+        if self.has_items() {
+            return Poll::Ready(Ok(true));
+        }
+
+        if self.chan.is_closed() {
+            return Poll::Ready(Ok(false));
+        }
+
+        self.chan.waker.register(cx.waker());
+        Poll::Pending
+    }
+
+    /// Drains the scratch slot and the channel's `ArrayQueue` directly into
+    /// `buf`, without going through an intermediate allocation. Shared by
+    /// the `AsyncRead` impl and the zero-copy `chan_read` pyfunction.
+    pub(crate) fn poll_fill(
+        &mut self,
         cx: &mut Context<'_>,
         mut buf: &mut [u8],
-    ) -> Poll<std::io::Result<usize>> {
+    ) -> Poll<io::Result<usize>> {
         if !self.has_items() {
             if self.chan.is_closed() {
                 return Poll::Ready(Ok(0));
@@ -77,7 +172,9 @@ impl AsyncRead for PyBytesReader {
 
         // If there's still space in buf, pop an item from the chan buffer
         // and continue writing
+        let mut popped = false;
         while let Some(bytes) = self.chan.buf.pop() {
+            popped = true;
             let bytes_slice = Python::with_gil(|py| bytes.as_bytes(py));
             let m = buf.write(bytes_slice).unwrap();
             n += m;
@@ -89,6 +186,22 @@ impl AsyncRead for PyBytesReader {
             }
         }
 
+        if popped {
+            // Draining the queue frees up capacity, so wake anyone parked
+            // in `PyBytesWriter::poll_write` on a full queue.
+            self.chan.waker.wake();
+        }
+
         Poll::Ready(Ok(n))
     }
 }
+
+impl AsyncRead for PyBytesReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.get_mut().poll_fill(cx, buf)
+    }
+}
@@ -0,0 +1,61 @@
+use std::io;
+
+use self::py_bytes::PyBytesSender;
+
+use super::*;
+
+impl PyBytesSender {
+    pub fn into_writer(self) -> PyBytesWriter {
+        PyBytesWriter::new(self.writer.inner)
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct PyBytesWriter {
+    chan: Arc<PyChanInner<PyBytes>>,
+}
+
+impl PyBytesWriter {
+    pub(crate) fn new(chan: Arc<PyChanInner<PyBytes>>) -> Self {
+        Self { chan }
+    }
+}
+
+impl AsyncWrite for PyBytesWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        if self.chan.buf.is_full() {
+            self.chan.waker.register(cx.waker());
+            return Poll::Pending;
+        }
+
+        let bytes = Python::with_gil(|py| PyBytes::new_bound(py, buf).unbind());
+        let n = buf.len();
+
+        match self.chan.buf.push(bytes) {
+            Ok(()) => {
+                self.chan.waker.wake();
+                Poll::Ready(Ok(n))
+            }
+            Err(_) => {
+                self.chan.waker.register(cx.waker());
+                Poll::Pending
+            }
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.chan.waker.wake();
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.chan.closed.store(true, Ordering::Release);
+        self.chan.waker.wake();
+        Poll::Ready(Ok(()))
+    }
+}